@@ -0,0 +1,34 @@
+use crate::git::{raw, Repository};
+use std::marker::PhantomData;
+use std::slice;
+
+/// The raw byte content of a file at a given revision, obtained via
+/// [`Repository::find_blob`].
+pub struct Blob<'repo> {
+    raw: *mut raw::git_blob,
+    _marker: PhantomData<&'repo Repository>,
+}
+
+impl<'repo> Blob<'repo> {
+    pub(crate) fn from_raw(raw: *mut raw::git_blob) -> Self {
+        Blob {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The uninterpreted bytes making up this blob.
+    pub fn content(&self) -> &[u8] {
+        unsafe {
+            let data = raw::git_blob_rawcontent(self.raw);
+            let len = raw::git_blob_rawsize(self.raw);
+            slice::from_raw_parts(data as *const u8, len as usize)
+        }
+    }
+}
+
+impl<'repo> Drop for Blob<'repo> {
+    fn drop(&mut self) {
+        unsafe { raw::git_blob_free(self.raw) }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::git::{check, raw, GitResult};
+use std::ffi::{CStr, CString};
+use std::mem;
+
+/// Read-only view over a repository's configuration (`.git/config`, the
+/// user's `~/.gitconfig`, and any other layers libgit2 merges in), obtained
+/// via [`crate::git::Repository::config`].
+pub struct Config {
+    raw: *mut raw::git_config,
+}
+
+impl Config {
+    pub(crate) fn from_raw(raw: *mut raw::git_config) -> Self {
+        Config { raw }
+    }
+
+    pub fn get_string(&self, name: &str) -> GitResult<String> {
+        let name = CString::new(name)?;
+        unsafe {
+            let mut value: *const libc::c_char = std::ptr::null();
+            check(raw::git_config_get_string(
+                &mut value,
+                self.raw,
+                name.as_ptr(),
+            ))?;
+            Ok(CStr::from_ptr(value).to_string_lossy().into_owned())
+        }
+    }
+
+    pub fn get_bool(&self, name: &str) -> GitResult<bool> {
+        let name = CString::new(name)?;
+        unsafe {
+            let mut value = mem::MaybeUninit::uninit();
+            check(raw::git_config_get_bool(
+                value.as_mut_ptr(),
+                self.raw,
+                name.as_ptr(),
+            ))?;
+            Ok(value.assume_init() != 0)
+        }
+    }
+
+    pub fn get_i64(&self, name: &str) -> GitResult<i64> {
+        let name = CString::new(name)?;
+        unsafe {
+            let mut value = mem::MaybeUninit::uninit();
+            check(raw::git_config_get_int64(
+                value.as_mut_ptr(),
+                self.raw,
+                name.as_ptr(),
+            ))?;
+            Ok(value.assume_init())
+        }
+    }
+}
+
+impl Drop for Config {
+    fn drop(&mut self) {
+        unsafe { raw::git_config_free(self.raw) }
+    }
+}
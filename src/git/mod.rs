@@ -1,13 +1,28 @@
-use libc::{c_char, c_int};
+use libc::{c_char, c_int, c_uint};
 use std::borrow::{Borrow, BorrowMut};
 use std::ffi::{CStr, CString, NulError};
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::path::Path;
+use std::str::FromStr;
 use std::sync::Once;
 use std::{error, mem, ptr};
 
+mod blob;
+mod config;
 mod raw;
+mod reference;
+mod revwalk;
+mod status;
+mod tree;
+
+pub use blob::Blob;
+pub use config::Config;
+pub use reference::{Reference, References};
+pub use revwalk::{Revwalk, Sort};
+pub use status::{Status, StatusEntry, StatusOptions, Statuses, StatusesIter};
+pub use tree::{ObjectKind, Tree, TreeEntry};
 
 #[derive(Debug)]
 pub struct GitError {
@@ -21,6 +36,59 @@ pub struct Oid {
     raw: raw::git_oid,
 }
 
+impl Oid {
+    /// The raw 20-byte SHA-1 digest.
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.raw.id
+    }
+}
+
+impl Display for Oid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut buf = [0 as c_char; 41];
+        unsafe {
+            raw::git_oid_tostr(buf.as_mut_ptr(), buf.len(), &self.raw);
+            f.write_str(&CStr::from_ptr(buf.as_ptr()).to_string_lossy())
+        }
+    }
+}
+
+impl FromStr for Oid {
+    type Err = GitError;
+
+    fn from_str(s: &str) -> GitResult<Oid> {
+        if s.len() > 40 || !s.bytes().all(|b| (b as char).is_ascii_hexdigit()) {
+            return Err(format!("'{}' is not a valid object id", s).into());
+        }
+        let cstr = CString::new(s)?;
+        unsafe {
+            let mut oid = mem::MaybeUninit::uninit();
+            check(raw::git_oid_fromstrn(
+                oid.as_mut_ptr(),
+                cstr.as_ptr(),
+                s.len(),
+            ))?;
+            Ok(Oid {
+                raw: oid.assume_init(),
+            })
+        }
+    }
+}
+
+impl PartialEq for Oid {
+    fn eq(&self, other: &Oid) -> bool {
+        unsafe { raw::git_oid_cmp(&self.raw, &other.raw) == 0 }
+    }
+}
+
+impl Eq for Oid {}
+
+impl Hash for Oid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state)
+    }
+}
+
 impl Display for GitError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         self.message.fmt(f)
@@ -70,6 +138,57 @@ impl<'repo> Commit<'repo> {
     pub fn message(&self) -> Option<&str> {
         unsafe { char_ptr_to_string(self, raw::git_commit_message(self.raw)) }
     }
+
+    pub fn committer(&self) -> Signature {
+        unsafe {
+            Signature {
+                raw: raw::git_commit_committer(self.raw),
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    /// Seconds since the Unix epoch at which this commit was committed (the
+    /// committer time, not the author time).
+    pub fn time(&self) -> i64 {
+        unsafe { raw::git_commit_time(self.raw) }
+    }
+
+    /// Number of parents this commit has (0 for the root commit, >1 for a
+    /// merge commit).
+    pub fn parent_count(&self) -> usize {
+        unsafe { raw::git_commit_parentcount(self.raw) as usize }
+    }
+
+    pub fn parent(&self, n: usize) -> GitResult<Commit<'repo>> {
+        let mut parent: *mut raw::git_commit = ptr::null_mut();
+        unsafe {
+            check(raw::git_commit_parent(&mut parent, self.raw, n as c_uint))?;
+        }
+        Ok(Commit {
+            raw: parent,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn parent_id(&self, n: usize) -> GitResult<Oid> {
+        unsafe {
+            let oid = raw::git_commit_parent_id(self.raw, n as c_uint);
+            if oid.is_null() {
+                return Err(format!("Commit has no parent at index {}", n).into());
+            }
+            Ok(Oid { raw: *oid })
+        }
+    }
+
+    /// The tree of files this commit points at.
+    pub fn tree(&self) -> GitResult<Tree<'repo>> {
+        let mut tree: *mut raw::git_tree = ptr::null_mut();
+        unsafe {
+            check(raw::git_commit_tree(&mut tree, self.raw))?;
+        }
+        Ok(Tree::from_raw(tree))
+    }
 }
 
 unsafe fn char_ptr_to_string<T>(_owner: &T, ptr: *const c_char) -> Option<&str> {
@@ -128,6 +247,66 @@ impl Repository {
         Ok(Oid { raw: oid })
     }
 
+    /// Start a new commit-history traversal rooted at this repository.
+    ///
+    /// The returned [`Revwalk`] is unseeded; call `push`/`push_ref`/
+    /// `push_glob` before iterating it.
+    pub fn revwalk(&self) -> GitResult<Revwalk> {
+        Revwalk::new(self)
+    }
+
+    /// Open this repository's merged configuration (repo, global, system).
+    pub fn config(&self) -> GitResult<Config> {
+        let mut config: *mut raw::git_config = ptr::null_mut();
+        unsafe {
+            check(raw::git_repository_config(&mut config, self.raw))?;
+        }
+        Ok(Config::from_raw(config))
+    }
+
+    /// Look up a reference by its full name, e.g. `refs/heads/main`.
+    pub fn find_reference(&self, name: &str) -> GitResult<Reference> {
+        let name = CString::new(name)?;
+        let mut reference: *mut raw::git_reference = ptr::null_mut();
+        unsafe {
+            check(raw::git_reference_lookup(
+                &mut reference,
+                self.raw,
+                name.as_ptr(),
+            ))?;
+        }
+        Ok(Reference::from_raw(reference))
+    }
+
+    /// The reference `HEAD` currently points at.
+    pub fn head(&self) -> GitResult<Reference> {
+        let mut reference: *mut raw::git_reference = ptr::null_mut();
+        unsafe {
+            check(raw::git_repository_head(&mut reference, self.raw))?;
+        }
+        Ok(Reference::from_raw(reference))
+    }
+
+    /// Iterate over every reference in the repository.
+    pub fn references(&self) -> GitResult<References> {
+        References::new(self)
+    }
+
+    /// Enumerate how files differ between `HEAD`, the index and the working
+    /// directory, similar to `git status`.
+    pub fn statuses(&self, opts: Option<&StatusOptions>) -> GitResult<Statuses> {
+        Statuses::new(self, opts)
+    }
+
+    /// Look up the blob `oid` points at, giving access to its raw content.
+    pub fn find_blob(&self, oid: &Oid) -> GitResult<Blob> {
+        let mut blob: *mut raw::git_blob = ptr::null_mut();
+        unsafe {
+            check(raw::git_blob_lookup(&mut blob, self.raw, &oid.raw))?;
+        }
+        Ok(Blob::from_raw(blob))
+    }
+
     pub fn find_commit(&self, oid: &Oid) -> GitResult<Commit> {
         let mut commit: *mut raw::git_commit = ptr::null_mut();
         unsafe {
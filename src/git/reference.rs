@@ -0,0 +1,104 @@
+use crate::git::{char_ptr_to_string, check, raw, GitResult, Oid, Repository};
+use std::marker::PhantomData;
+use std::ptr;
+
+/// A named pointer into the object database -- a branch, tag, or `HEAD` --
+/// as opposed to the bare [`Oid`] returned by
+/// [`Repository::reference_name_to_oid`].
+pub struct Reference<'repo> {
+    raw: *mut raw::git_reference,
+    _marker: PhantomData<&'repo Repository>,
+}
+
+impl<'repo> Reference<'repo> {
+    pub(crate) fn from_raw(raw: *mut raw::git_reference) -> Self {
+        Reference {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The reference's full name, e.g. `refs/heads/main`.
+    pub fn name(&self) -> Option<&str> {
+        unsafe { char_ptr_to_string(self, raw::git_reference_name(self.raw)) }
+    }
+
+    /// A human-friendly form of the name, e.g. `main` instead of
+    /// `refs/heads/main`.
+    pub fn shorthand(&self) -> Option<&str> {
+        unsafe { char_ptr_to_string(self, raw::git_reference_shorthand(self.raw)) }
+    }
+
+    /// The `Oid` this reference points at directly, or `None` if it is a
+    /// symbolic reference (use [`resolve`](Reference::resolve) for those).
+    pub fn target(&self) -> Option<Oid> {
+        unsafe {
+            let oid = raw::git_reference_target(self.raw);
+            if oid.is_null() {
+                None
+            } else {
+                Some(Oid { raw: *oid })
+            }
+        }
+    }
+
+    /// Follow a symbolic reference (like `HEAD`) to the direct reference it
+    /// ultimately points at.
+    pub fn resolve(&self) -> GitResult<Reference<'repo>> {
+        let mut resolved: *mut raw::git_reference = ptr::null_mut();
+        unsafe {
+            check(raw::git_reference_resolve(&mut resolved, self.raw))?;
+        }
+        Ok(Reference::from_raw(resolved))
+    }
+}
+
+impl<'repo> Drop for Reference<'repo> {
+    fn drop(&mut self) {
+        unsafe { raw::git_reference_free(self.raw) }
+    }
+}
+
+/// Iterator over every reference in a repository, obtained via
+/// [`Repository::references`].
+pub struct References<'repo> {
+    raw: *mut raw::git_reference_iterator,
+    _marker: PhantomData<&'repo Repository>,
+}
+
+impl<'repo> References<'repo> {
+    pub(crate) fn new(repo: &'repo Repository) -> GitResult<Self> {
+        let mut iter: *mut raw::git_reference_iterator = ptr::null_mut();
+        unsafe {
+            check(raw::git_reference_iterator_new(&mut iter, repo.raw))?;
+        }
+        Ok(References {
+            raw: iter,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'repo> Iterator for References<'repo> {
+    type Item = GitResult<Reference<'repo>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut reference: *mut raw::git_reference = ptr::null_mut();
+        unsafe {
+            let code = raw::git_reference_next(&mut reference, self.raw);
+            if code == raw::GIT_ITEROVER {
+                return None;
+            }
+            match check(code) {
+                Ok(_) => Some(Ok(Reference::from_raw(reference))),
+                Err(e) => Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<'repo> Drop for References<'repo> {
+    fn drop(&mut self) {
+        unsafe { raw::git_reference_iterator_free(self.raw) }
+    }
+}
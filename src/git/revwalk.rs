@@ -0,0 +1,124 @@
+use crate::git::{check, raw, GitResult, Oid, Repository};
+use libc::c_uint;
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::ptr;
+
+/// Flags controlling the order in which a [`Revwalk`] yields commits.
+///
+/// These correspond to libgit2's `git_sort_t` and can be combined with
+/// bitwise-or, e.g. `Sort::TOPOLOGICAL | Sort::REVERSE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sort(u32);
+
+impl Sort {
+    pub const NONE: Sort = Sort(0);
+    pub const TOPOLOGICAL: Sort = Sort(1 << 0);
+    pub const TIME: Sort = Sort(1 << 1);
+    pub const REVERSE: Sort = Sort(1 << 2);
+
+    fn bits(self) -> c_uint {
+        self.0 as c_uint
+    }
+}
+
+impl std::ops::BitOr for Sort {
+    type Output = Sort;
+
+    fn bitor(self, rhs: Sort) -> Sort {
+        Sort(self.0 | rhs.0)
+    }
+}
+
+/// Iterator over the commit ancestry of a [`Repository`], obtained via
+/// [`Repository::revwalk`].
+///
+/// Mirrors libgit2's `git_revwalk` handle: seed it with [`push`](Revwalk::push)
+/// / [`push_ref`](Revwalk::push_ref) / [`push_glob`](Revwalk::push_glob),
+/// optionally exclude ancestors with [`hide`](Revwalk::hide), then iterate to
+/// walk the DAG one [`Oid`] at a time.
+pub struct Revwalk<'repo> {
+    raw: *mut raw::git_revwalk,
+    _marker: PhantomData<&'repo Repository>,
+}
+
+impl<'repo> Revwalk<'repo> {
+    pub(crate) fn new(repo: &'repo Repository) -> GitResult<Self> {
+        let mut walk: *mut raw::git_revwalk = ptr::null_mut();
+        unsafe {
+            check(raw::git_revwalk_new(&mut walk, repo.raw))?;
+        }
+        Ok(Revwalk {
+            raw: walk,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Mark `oid` as a starting point for the traversal.
+    pub fn push(&mut self, oid: &Oid) -> GitResult<()> {
+        unsafe {
+            check(raw::git_revwalk_push(self.raw, &oid.raw))?;
+        }
+        Ok(())
+    }
+
+    /// Mark the commit pointed at by `refname` as a starting point.
+    pub fn push_ref(&mut self, refname: &str) -> GitResult<()> {
+        let refname = CString::new(refname)?;
+        unsafe {
+            check(raw::git_revwalk_push_ref(self.raw, refname.as_ptr()))?;
+        }
+        Ok(())
+    }
+
+    /// Mark every reference matching `glob` as a starting point.
+    pub fn push_glob(&mut self, glob: &str) -> GitResult<()> {
+        let glob = CString::new(glob)?;
+        unsafe {
+            check(raw::git_revwalk_push_glob(self.raw, glob.as_ptr()))?;
+        }
+        Ok(())
+    }
+
+    /// Exclude `oid` and its ancestors from the traversal.
+    pub fn hide(&mut self, oid: &Oid) -> GitResult<()> {
+        unsafe {
+            check(raw::git_revwalk_hide(self.raw, &oid.raw))?;
+        }
+        Ok(())
+    }
+
+    /// Set the order in which commits are returned.
+    pub fn set_sorting(&mut self, sort: Sort) -> GitResult<()> {
+        unsafe {
+            check(raw::git_revwalk_sorting(self.raw, sort.bits()))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'repo> Iterator for Revwalk<'repo> {
+    type Item = GitResult<Oid>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let mut oid = std::mem::MaybeUninit::uninit();
+            let code = raw::git_revwalk_next(oid.as_mut_ptr(), self.raw);
+            if code == raw::GIT_ITEROVER {
+                return None;
+            }
+            match check(code) {
+                Ok(_) => Some(Ok(Oid {
+                    raw: oid.assume_init(),
+                })),
+                Err(e) => Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<'repo> Drop for Revwalk<'repo> {
+    fn drop(&mut self) {
+        unsafe { raw::git_revwalk_free(self.raw) }
+    }
+}
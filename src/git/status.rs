@@ -0,0 +1,192 @@
+use crate::git::{char_ptr_to_string, check, raw, GitResult, Repository};
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr;
+
+/// Bitflags describing how an entry differs between `HEAD`, the index and
+/// the working directory, mirroring libgit2's `GIT_STATUS_*` constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Status(u32);
+
+impl Status {
+    pub const CURRENT: Status = Status(0);
+    pub const INDEX_NEW: Status = Status(1 << 0);
+    pub const INDEX_MODIFIED: Status = Status(1 << 1);
+    pub const INDEX_DELETED: Status = Status(1 << 2);
+    pub const INDEX_RENAMED: Status = Status(1 << 3);
+    pub const WT_NEW: Status = Status(1 << 7);
+    pub const WT_MODIFIED: Status = Status(1 << 8);
+    pub const WT_DELETED: Status = Status(1 << 9);
+    pub const WT_RENAMED: Status = Status(1 << 11);
+
+    fn from_raw(bits: u32) -> Self {
+        Status(bits)
+    }
+
+    pub fn contains(self, other: Status) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for Status {
+    type Output = Status;
+
+    fn bitor(self, rhs: Status) -> Status {
+        Status(self.0 | rhs.0)
+    }
+}
+
+/// Builder for the options passed to [`Repository::statuses`].
+///
+/// Defaults match libgit2's own defaults: untracked and ignored files are
+/// left out unless explicitly requested.
+pub struct StatusOptions {
+    include_untracked: bool,
+    include_ignored: bool,
+}
+
+impl StatusOptions {
+    pub fn new() -> Self {
+        StatusOptions {
+            include_untracked: false,
+            include_ignored: false,
+        }
+    }
+
+    pub fn include_untracked(&mut self, include: bool) -> &mut Self {
+        self.include_untracked = include;
+        self
+    }
+
+    pub fn include_ignored(&mut self, include: bool) -> &mut Self {
+        self.include_ignored = include;
+        self
+    }
+
+    fn to_raw(&self) -> GitResult<raw::git_status_options> {
+        unsafe {
+            let mut opts = mem::MaybeUninit::uninit();
+            check(raw::git_status_init_options(
+                opts.as_mut_ptr(),
+                raw::GIT_STATUS_OPTIONS_VERSION,
+            ))?;
+            let mut opts = opts.assume_init();
+            if self.include_untracked {
+                opts.flags |= raw::GIT_STATUS_OPT_INCLUDE_UNTRACKED;
+            }
+            if self.include_ignored {
+                opts.flags |= raw::GIT_STATUS_OPT_INCLUDE_IGNORED;
+            }
+            Ok(opts)
+        }
+    }
+}
+
+impl Default for StatusOptions {
+    fn default() -> Self {
+        StatusOptions::new()
+    }
+}
+
+/// A `git status`-style snapshot of how the working directory and index
+/// differ from `HEAD`, obtained via [`Repository::statuses`].
+pub struct Statuses<'repo> {
+    raw: *mut raw::git_status_list,
+    _marker: PhantomData<&'repo Repository>,
+}
+
+impl<'repo> Statuses<'repo> {
+    pub(crate) fn new(repo: &'repo Repository, opts: Option<&StatusOptions>) -> GitResult<Self> {
+        let owned;
+        let mut raw_opts = match opts {
+            Some(opts) => opts.to_raw()?,
+            None => {
+                owned = StatusOptions::new();
+                owned.to_raw()?
+            }
+        };
+        let mut list: *mut raw::git_status_list = ptr::null_mut();
+        unsafe {
+            check(raw::git_status_list_new(&mut list, repo.raw, &mut raw_opts))?;
+        }
+        Ok(Statuses {
+            raw: list,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe { raw::git_status_list_entrycount(self.raw) as usize }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter<'a>(&'a self) -> StatusesIter<'a, 'repo> {
+        StatusesIter {
+            statuses: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'repo> Drop for Statuses<'repo> {
+    fn drop(&mut self) {
+        unsafe { raw::git_status_list_free(self.raw) }
+    }
+}
+
+/// Iterator over the entries of a [`Statuses`] list.
+pub struct StatusesIter<'a, 'repo> {
+    statuses: &'a Statuses<'repo>,
+    index: usize,
+}
+
+impl<'a, 'repo> Iterator for StatusesIter<'a, 'repo> {
+    type Item = StatusEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.statuses.len() {
+            return None;
+        }
+        let entry = unsafe { raw::git_status_byindex(self.statuses.raw, self.index) };
+        self.index += 1;
+        if entry.is_null() {
+            None
+        } else {
+            Some(StatusEntry {
+                raw: entry,
+                _marker: PhantomData,
+            })
+        }
+    }
+}
+
+/// A single file's status, borrowed from a [`Statuses`] list.
+pub struct StatusEntry<'list> {
+    raw: *const raw::git_status_entry,
+    _marker: PhantomData<&'list ()>,
+}
+
+impl<'list> StatusEntry<'list> {
+    pub fn path(&self) -> Option<&str> {
+        unsafe {
+            let entry = &*self.raw;
+            let delta = if !entry.index_to_workdir.is_null() {
+                entry.index_to_workdir
+            } else {
+                entry.head_to_index
+            };
+            if delta.is_null() {
+                None
+            } else {
+                char_ptr_to_string(self, (*delta).new_file.path)
+            }
+        }
+    }
+
+    pub fn status(&self) -> Status {
+        unsafe { Status::from_raw((*self.raw).status) }
+    }
+}
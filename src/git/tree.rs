@@ -0,0 +1,108 @@
+use crate::git::{char_ptr_to_string, raw, GitResult, Oid, Repository};
+use std::ffi::CString;
+use std::marker::PhantomData;
+
+/// The set of files and subdirectories pointed at by a commit, as of the
+/// point in history that commit represents.
+///
+/// Obtained via [`crate::git::Commit::tree`].
+pub struct Tree<'repo> {
+    raw: *mut raw::git_tree,
+    _marker: PhantomData<&'repo Repository>,
+}
+
+impl<'repo> Tree<'repo> {
+    pub(crate) fn from_raw(raw: *mut raw::git_tree) -> Self {
+        Tree {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Number of entries in this tree.
+    pub fn len(&self) -> usize {
+        unsafe { raw::git_tree_entrycount(self.raw) as usize }
+    }
+
+    /// The entry at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<TreeEntry> {
+        unsafe {
+            let entry = raw::git_tree_entry_byindex(self.raw, index);
+            if entry.is_null() {
+                None
+            } else {
+                Some(TreeEntry {
+                    raw: entry,
+                    _marker: PhantomData,
+                })
+            }
+        }
+    }
+
+    /// The entry named `name` directly under this tree, or `None` if no such
+    /// entry exists.
+    pub fn get_name(&self, name: &str) -> GitResult<Option<TreeEntry>> {
+        let name = CString::new(name)?;
+        unsafe {
+            let entry = raw::git_tree_entry_byname(self.raw, name.as_ptr());
+            Ok(if entry.is_null() {
+                None
+            } else {
+                Some(TreeEntry {
+                    raw: entry,
+                    _marker: PhantomData,
+                })
+            })
+        }
+    }
+}
+
+impl<'repo> Drop for Tree<'repo> {
+    fn drop(&mut self) {
+        unsafe { raw::git_tree_free(self.raw) }
+    }
+}
+
+/// The kind of object a [`TreeEntry`] points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectKind {
+    Blob,
+    Tree,
+    Commit,
+    Other,
+}
+
+impl ObjectKind {
+    fn from_raw(kind: raw::git_otype) -> Self {
+        match kind {
+            raw::GIT_OBJ_BLOB => ObjectKind::Blob,
+            raw::GIT_OBJ_TREE => ObjectKind::Tree,
+            raw::GIT_OBJ_COMMIT => ObjectKind::Commit,
+            _ => ObjectKind::Other,
+        }
+    }
+}
+
+/// A single named entry within a [`Tree`], borrowed from it.
+pub struct TreeEntry<'tree> {
+    raw: *const raw::git_tree_entry,
+    _marker: PhantomData<&'tree ()>,
+}
+
+impl<'tree> TreeEntry<'tree> {
+    pub fn name(&self) -> Option<&str> {
+        unsafe { char_ptr_to_string(self, raw::git_tree_entry_name(self.raw)) }
+    }
+
+    pub fn id(&self) -> Oid {
+        unsafe {
+            Oid {
+                raw: *raw::git_tree_entry_id(self.raw),
+            }
+        }
+    }
+
+    pub fn kind(&self) -> ObjectKind {
+        unsafe { ObjectKind::from_raw(raw::git_tree_entry_type(self.raw)) }
+    }
+}
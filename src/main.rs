@@ -15,10 +15,22 @@ fn main() {
         .expect("Failed to get OID");
     let commit = repo.find_commit(&oid).expect("Failed to get commit");
     let author = commit.author();
-    println!(
-        "{} <{}>",
-        author.name().unwrap_or(NONE),
-        author.email().unwrap_or(NONE)
-    );
+    let config = repo.config().ok();
+    let name = author
+        .name()
+        .map(String::from)
+        .or_else(|| config.as_ref().and_then(|c| c.get_string("user.name").ok()))
+        .unwrap_or_else(|| NONE.to_string());
+    let email = author
+        .email()
+        .map(String::from)
+        .or_else(|| {
+            config
+                .as_ref()
+                .and_then(|c| c.get_string("user.email").ok())
+        })
+        .unwrap_or_else(|| NONE.to_string());
+    println!("commit {}", oid);
+    println!("{} <{}>", name, email);
     println!("{}", commit.message().unwrap_or(NONE));
 }